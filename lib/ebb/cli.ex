@@ -12,11 +12,140 @@ defmodule Ebb.CLI do
   @seconds_per_hour 3600
   @seconds_per_minutes 60
 
+  @days_off_switches [
+    year: :integer,
+    month: :integer,
+    format: :string,
+    remaining_only: :boolean,
+    category: :string
+  ]
+
+  @formats ["text", "json"]
+  @categories ["vacation", "sick"]
+
   @doc """
   Main function for the escript.
   """
-  def main(["balance" | _]) do
+  def main(["balance" | args]) do
+    opts = parse_options!(args, project: :string)
+    config = Configuration.read_config()
+
+    case Keyword.fetch(opts, :project) do
+      {:ok, project} -> print_project_balance(project, config)
+      :error -> print_balance(config)
+    end
+  end
+
+  def main(["daysoff" | args]) do
+    opts = parse_options!(args, @days_off_switches)
+    validate_month!(opts[:month])
+    validate_days_off_options!(opts)
+    format = opts |> Keyword.get(:format, "text") |> validate_format!()
+    config = Configuration.read_config()
+
+    year =
+      Keyword.get_lazy(opts, :year, fn ->
+        DateTime.now!(config.time_zone).year
+      end)
+
+    cond do
+      Keyword.has_key?(opts, :month) ->
+        print_days_off_for_month(year, opts[:month], config, format)
+
+      Keyword.get(opts, :remaining_only, false) ->
+        print_remaining_days_off(year, config, format, opts[:category])
+
+      true ->
+        print_days_off_for_year(year, config, format)
+    end
+  end
+
+  def main(["config", "validate" | _]) do
+    Configuration.read_config()
+    IO.puts("Configuration is valid.")
+  rescue
+    e ->
+      IO.puts(:stderr, Exception.message(e))
+      exit({:shutdown, 1})
+  end
+
+  def main(["config" | _]) do
     config = Configuration.read_config()
+    IO.puts("#{inspect(config, pretty: true)}")
+  end
+
+  def main(_) do
+    IO.puts("""
+    ebb balance - Print current time balance.
+      --project PROJECT    Print the time balance of a single project.
+    ebb daysoff - Print days off for the current year.
+      --year YEAR          Print days off for the given year.
+      --month MONTH        Print days off taken in the given month.
+      --remaining-only     Only print the number of vacation days left.
+      --category CATEGORY  With --remaining-only, either vacation or sick.
+      --format FORMAT      Either text or json.
+    ebb config - Print configuration.
+    ebb config validate - Validate configuration.
+    """)
+  end
+
+  defp parse_options!(args, switches) do
+    case OptionParser.parse(args, strict: switches) do
+      {opts, _, []} ->
+        opts
+
+      {_, _, invalid} ->
+        options = Enum.map_join(invalid, ", ", &format_invalid_option/1)
+        usage_error!("Invalid options: #{options}")
+    end
+  end
+
+  defp format_invalid_option({name, nil}), do: name
+  defp format_invalid_option({name, value}), do: "#{name} #{value}"
+
+  defp validate_month!(nil), do: :ok
+  defp validate_month!(month) when month in 1..12, do: :ok
+
+  defp validate_month!(month) do
+    usage_error!("Invalid month: #{month}. The month must be between 1 and 12.")
+  end
+
+  defp validate_days_off_options!(opts) do
+    remaining_only = Keyword.get(opts, :remaining_only, false)
+    category = Keyword.get(opts, :category)
+
+    cond do
+      remaining_only and Keyword.has_key?(opts, :month) ->
+        usage_error!("The --remaining-only option cannot be used with --month.")
+
+      category && not remaining_only ->
+        usage_error!("The --category option requires --remaining-only.")
+
+      category && category not in @categories ->
+        allowed = Enum.join(@categories, ", ")
+
+        usage_error!(
+          "Invalid category: #{category}. Allowed categories: #{allowed}."
+        )
+
+      true ->
+        :ok
+    end
+  end
+
+  defp validate_format!(format) when format in @formats, do: format
+
+  defp validate_format!(format) do
+    allowed = Enum.join(@formats, ", ")
+    usage_error!("Invalid format: #{format}. Allowed formats: #{allowed}.")
+  end
+
+  defp usage_error!(message) do
+    IO.puts(:stderr, message <> "\n\nRun `ebb help` for usage.")
+    exit({:shutdown, 1})
+  end
+
+  defp print_balance(config) do
     today = config.time_zone |> DateTime.now!() |> DateTime.to_date()
 
     %{start_date: start_date, total_time_in_seconds: total_time_in_seconds} =
@@ -35,59 +164,157 @@ defmodule Ebb.CLI do
       {"Expected", seconds_to_duration(expected_work_seconds)},
       {"Actual", seconds_to_duration(total_time_in_seconds)},
       :divider,
-      {"Balance", seconds_to_duration(diff_seconds)}
+      {"Balance", seconds_to_balance(diff_seconds)}
     ])
   end
 
-  def main(["daysoff" | _]) do
-    config = Configuration.read_config()
-    year = DateTime.now!(config.time_zone).year
+  defp print_project_balance(project, config) do
+    today = config.time_zone |> DateTime.now!() |> DateTime.to_date()
 
-    %{
-      allowed: allowed_vacation_days,
-      taken: taken_vacation_days,
-      left: vacation_days_left
-    } = DaysOff.calculate_vacation_days(year, config)
+    %{start_date: start_date, total_time_in_seconds: total_time_in_seconds} =
+      Watson.report(config, project: project)
 
-    %{
-      allowed: allowed_sick_days,
-      taken: taken_sick_days,
-      left: sick_days_left
-    } = DaysOff.calculate_sick_days(year, config)
+    target_rows =
+      case Map.fetch(config.project_targets_in_seconds, project) do
+        {:ok, target_seconds} ->
+          diff_seconds = total_time_in_seconds - target_seconds
 
-    print_table([{"Year", to_string(year)}])
+          [
+            {"Target", seconds_to_duration(target_seconds)},
+            {"Actual", seconds_to_duration(total_time_in_seconds)},
+            :divider,
+            {"Balance", seconds_to_balance(diff_seconds)}
+          ]
 
-    IO.puts("\n\nSick days\n")
+        :error ->
+          [{"Actual", seconds_to_duration(total_time_in_seconds)}]
+      end
 
-    print_table([
-      {"Allowed", to_string(allowed_sick_days)},
-      {"Taken", to_string(taken_sick_days)},
-      :divider,
-      {"Left", to_string(sick_days_left)}
-    ])
+    IO.puts("Project balance\n")
+
+    print_table(
+      [
+        {"Project", project},
+        {"Start date", Date.to_string(start_date)},
+        {"End date", Date.to_string(today)}
+      ] ++ target_rows
+    )
+  end
+
+  defp print_days_off_for_year(year, config, format) do
+    sick_days = DaysOff.calculate_sick_days(year, config)
+    vacation_days = DaysOff.calculate_vacation_days(year, config)
 
-    IO.puts("\n\nVacation days\n")
+    case format do
+      "text" ->
+        print_table([{"Year", to_string(year)}])
+        IO.puts("\n\nSick days\n")
+        print_days_off_table(sick_days)
+        IO.puts("\n\nVacation days\n")
+        print_days_off_table(vacation_days)
+
+      "json" ->
+        print_json(%{
+          year: year,
+          sick_days_allowed: sick_days.allowed,
+          sick_days_taken: sick_days.taken,
+          sick_days_remaining: sick_days.left,
+          vacation_days_allowed: vacation_days.allowed,
+          vacation_days_taken: vacation_days.taken,
+          vacation_days_remaining: vacation_days.left
+        })
+    end
+  end
+
+  defp print_days_off_for_month(year, month, config, format) do
+    first_date = Date.new!(year, month, 1)
+    last_date = Date.end_of_month(first_date)
+
+    working_days =
+      WorkingHours.count_working_days(first_date, last_date, config)
+
+    config = DaysOff.filter_by_month(config, month)
+
+    %{taken: taken_vacation_days} =
+      DaysOff.calculate_vacation_days(year, config)
+
+    %{taken: taken_sick_days} = DaysOff.calculate_sick_days(year, config)
+
+    case format do
+      "text" ->
+        print_table([
+          {"Month", Calendar.strftime(first_date, "%B %Y")},
+          {"Working days", to_string(working_days)}
+        ])
+
+        IO.puts("\n\nSick days\n")
+        print_table([{"Taken", format_number(taken_sick_days)}])
+        IO.puts("\n\nVacation days\n")
+        print_table([{"Taken", format_number(taken_vacation_days)}])
+
+      "json" ->
+        print_json(%{
+          year: year,
+          month: month,
+          working_days: working_days,
+          sick_days_taken: taken_sick_days,
+          vacation_days_taken: taken_vacation_days
+        })
+    end
+  end
 
+  defp print_remaining_days_off(year, config, format, category) do
+    sick_days = DaysOff.calculate_sick_days(year, config)
+    vacation_days = DaysOff.calculate_vacation_days(year, config)
+
+    case {format, category} do
+      {"text", "sick"} ->
+        IO.puts(format_number(sick_days.left))
+
+      {"text", category} when category in [nil, "vacation"] ->
+        IO.puts(format_number(vacation_days.left))
+
+      {"json", "sick"} ->
+        print_json(%{sick_days_remaining: sick_days.left})
+
+      {"json", "vacation"} ->
+        print_json(%{vacation_days_remaining: vacation_days.left})
+
+      {"json", nil} ->
+        print_json(%{
+          sick_days_remaining: sick_days.left,
+          vacation_days_remaining: vacation_days.left
+        })
+    end
+  end
+
+  defp print_days_off_table(%{allowed: allowed, taken: taken, left: left}) do
     print_table([
-      {"Allowed", to_string(allowed_vacation_days)},
-      {"Taken", to_string(taken_vacation_days)},
+      {"Allowed", format_number(allowed)},
+      {"Taken", format_number(taken)},
       :divider,
-      {"Left", to_string(vacation_days_left)}
+      {"Left", format_number(left)}
     ])
   end
 
-  def main(["config" | _]) do
-    config = Configuration.read_config()
-    IO.puts("#{inspect(config, pretty: true)}")
+  defp print_json(map) do
+    map
+    |> Map.new(fn {key, value} -> {key, normalize_number(value)} end)
+    |> Jason.encode!(pretty: true)
+    |> IO.puts()
   end
 
-  def main(_) do
-    IO.puts("""
-    ebb balance - Print current time balance.
-    ebb config - Print configuration.
-    """)
+  defp format_number(value) do
+    value
+    |> normalize_number()
+    |> to_string()
   end
 
+  defp normalize_number(value) when is_float(value) and value == round(value),
+    do: round(value)
+
+  defp normalize_number(value), do: value
+
   defp print_table(rows) do
     max_key_length = get_max_key_length(rows)
     max_value_length = get_max_value_length(rows)
@@ -152,6 +379,11 @@ defmodule Ebb.CLI do
     "#{sign}#{parts}"
   end
 
+  defp seconds_to_balance(seconds) do
+    duration = seconds_to_duration(seconds)
+    if round(seconds) > 0, do: "+" <> duration, else: duration
+  end
+
   defp pad_zeroes(value) when is_integer(value) do
     value
     |> to_string()
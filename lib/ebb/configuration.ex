@@ -11,33 +11,43 @@ defmodule Ebb.Configuration do
           time_zone: Calendar.time_zone(),
           start_date: Date.t(),
           time_adjustment_in_seconds: integer,
+          project_targets_in_seconds: %{optional(String.t()) => integer},
           working_days: working_days(),
           holidays: date_map(),
+          recurring_holidays: recurring_holidays(),
           vacation_days: date_map(),
           sick_days: date_map(),
-          allowed_days_off: allowed_days_off()
+          allowed_days_off: allowed_days_off(),
+          vacation_carryover: %{optional(integer) => number}
         }
 
   @type date_map :: %{optional(Date.t()) => String.t()}
-  @type allowed_days_off :: %{sick_days: integer, vacation_days: integer}
+  @type recurring_holidays :: %{
+          optional({Calendar.month(), Calendar.day()}) => String.t()
+        }
+  @type day_portion :: :full | :half | :quarter
+  @type allowed_days_off :: %{sick_days: number, vacation_days: number}
   @type working_days :: %{
-          1 => non_neg_integer,
-          2 => non_neg_integer,
-          3 => non_neg_integer,
-          4 => non_neg_integer,
-          5 => non_neg_integer,
-          6 => non_neg_integer,
-          7 => non_neg_integer
+          1 => number,
+          2 => number,
+          3 => number,
+          4 => number,
+          5 => number,
+          6 => number,
+          7 => number
         }
 
   defstruct time_zone: "Etc/UTC",
             start_date: ~D[3000-01-01],
             time_adjustment_in_seconds: 0,
+            project_targets_in_seconds: %{},
             working_days: %{},
             holidays: %{},
+            recurring_holidays: %{},
             vacation_days: %{},
             sick_days: %{},
-            allowed_days_off: %{}
+            allowed_days_off: %{},
+            vacation_carryover: %{}
 
   @doc """
   Reads, parses and validates the configuration file.
@@ -50,6 +60,70 @@ defmodule Ebb.Configuration do
     |> validate!()
   end
 
+  @doc """
+  Returns the holidays of the given year, including the recurring holidays.
+
+  Holidays set for a specific date take precedence over recurring holidays on
+  the same date.
+  """
+  @spec holidays_for_year(t(), integer) :: date_map()
+  def holidays_for_year(%__MODULE__{} = config, year) do
+    config.recurring_holidays
+    |> Enum.flat_map(fn {{month, day}, description} ->
+      case Date.new(year, month, day) do
+        {:ok, date} -> [{date, description}]
+        {:error, _} -> []
+      end
+    end)
+    |> Map.new()
+    |> Map.merge(Map.filter(config.holidays, &(elem(&1, 0).year == year)))
+  end
+
+  @doc """
+  Returns the holidays between the given dates (inclusive), including the
+  recurring holidays.
+  """
+  @spec holidays_between(t(), Date.t(), Date.t()) :: date_map()
+  def holidays_between(%__MODULE__{} = config, first_date, last_date) do
+    first_date.year..last_date.year//1
+    |> Enum.map(&holidays_for_year(config, &1))
+    |> Enum.reduce(%{}, &Map.merge/2)
+    |> Map.filter(fn {date, _} ->
+      Date.compare(date, first_date) != :lt and
+        Date.compare(date, last_date) != :gt
+    end)
+  end
+
+  @doc """
+  Returns the portion of the day covered by a day off, based on the suffix of
+  its description: ` (h)` for a half day, ` (q)` for a quarter day.
+
+  ## Example
+
+      iex> day_portion("Dentist (q)")
+      :quarter
+  """
+  @spec day_portion(String.t()) :: day_portion()
+  def day_portion(description) do
+    cond do
+      String.ends_with?(description, " (h)") -> :half
+      String.ends_with?(description, " (q)") -> :quarter
+      true -> :full
+    end
+  end
+
+  @doc """
+  Returns whether the given date is a full-day holiday in the given holiday
+  map.
+  """
+  @spec full_day_holiday?(date_map(), Date.t()) :: boolean
+  def full_day_holiday?(holidays, date) do
+    case Map.fetch(holidays, date) do
+      {:ok, description} -> day_portion(description) == :full
+      :error -> false
+    end
+  end
+
   defp config_path do
     "EBB_CONFIG_PATH"
     |> System.get_env(default_folder())
@@ -65,54 +139,239 @@ defmodule Ebb.Configuration do
   end
 
   defp validate!(map) do
-    %__MODULE__{
-      allowed_days_off: validate_allowed_days_off!(map),
-      holidays: validate_days!(map, "holidays"),
-      vacation_days: validate_days!(map, "vacation_days"),
-      sick_days: validate_days!(map, "sick_days"),
-      start_date: validate_start_date!(map),
-      time_adjustment_in_seconds: validate_time_adjustment!(map),
-      time_zone: validate_time_zone!(map),
+    config = %__MODULE__{
+      recurring_holidays: validate_recurring_holidays!(map),
       working_days: validate_working_days!(map)
     }
+
+    config = %{config | holidays: validate_days!(map, "holidays", config)}
+
+    %{
+      config
+      | allowed_days_off: validate_allowed_days_off!(map),
+        vacation_days: validate_days!(map, "vacation_days", config),
+        sick_days: validate_days!(map, "sick_days", config),
+        start_date: validate_start_date!(map),
+        time_adjustment_in_seconds: validate_time_adjustment!(map),
+        project_targets_in_seconds: validate_project_targets!(map),
+        time_zone: validate_time_zone!(map),
+        vacation_carryover: validate_vacation_carryover!(map)
+    }
   end
 
   defp validate_allowed_days_off!(%{"allowed_days_off" => map}) do
     %{
-      vacation_days: Map.fetch!(map, "vacation_days"),
-      sick_days: Map.fetch!(map, "sick_days")
+      vacation_days: fetch_allowed_days!(map, "vacation_days"),
+      sick_days: fetch_allowed_days!(map, "sick_days")
     }
   end
 
-  defp validate_days!(%{} = map, key) do
+  defp fetch_allowed_days!(map, key) do
+    days = Map.fetch!(map, key)
+
+    unless is_number(days) and days >= 0 do
+      raise """
+      Invalid number of allowed days off
+
+      The configuration file sets an invalid number of allowed #{key}:
+      #{inspect(days)}
+
+      The value must be a non-negative number.
+      """
+    end
+
+    days
+  end
+
+  defp validate_vacation_carryover!(%{} = map) do
+    case Map.get(map, "vacation_carryover") do
+      nil ->
+        %{}
+
+      %{} = carryover_map ->
+        Map.new(carryover_map, &validate_carryover_entry!/1)
+
+      carryover ->
+        raise """
+        Invalid vacation carry-over
+
+        The configuration file sets an invalid vacation carry-over:
+        #{inspect(carryover)}
+
+        The value must map years to the number of carried-over days.
+        """
+    end
+  end
+
+  defp validate_carryover_entry!({year, days}) do
+    unless is_integer(year) and is_number(days) and days >= 0 do
+      raise """
+      Invalid vacation carry-over
+
+      The configuration file sets an invalid vacation carry-over:
+      #{inspect(year)}: #{inspect(days)}
+
+      The key must be a year and the value a non-negative number.
+      """
+    end
+
+    {year, days}
+  end
+
+  defp validate_days!(%{} = map, key, %__MODULE__{} = config) do
     case Map.get(map, key) do
       nil ->
         %{}
 
       %{} = date_map ->
-        Enum.into(date_map, %{}, fn {date_str, description} ->
-          {Date.from_iso8601!(date_str), description}
+        {ranges, dates} =
+          Enum.split_with(date_map, fn {date_str, _} ->
+            String.contains?(date_str, "..")
+          end)
+
+        single_days =
+          Map.new(dates, fn {date_str, description} ->
+            {Date.from_iso8601!(date_str), description}
+          end)
+
+        ranges
+        |> Enum.flat_map(&expand_date_range!(&1, config))
+        |> Map.new()
+        |> Map.merge(single_days)
+    end
+  end
+
+  defp expand_date_range!({range_str, description}, config) do
+    range = parse_date_range!(range_str)
+    holidays = holidays_between(config, range.first, range.last)
+
+    range
+    |> Enum.filter(fn date ->
+      Map.fetch!(config.working_days, Date.day_of_week(date)) > 0 and
+        not full_day_holiday?(holidays, date)
+    end)
+    |> Enum.map(&{&1, description})
+  end
+
+  defp parse_date_range!(range_str) do
+    with [first_str, last_str] <- String.split(range_str, ".."),
+         {:ok, first_date} <- Date.from_iso8601(first_str),
+         {:ok, last_date} <- Date.from_iso8601(last_str),
+         true <- Date.compare(first_date, last_date) != :gt do
+      Date.range(first_date, last_date)
+    else
+      _ ->
+        raise """
+        Invalid date range
+
+        The configuration file sets an invalid date range: #{range_str}
+
+        The range must be given as two dates like 2023-08-14..2023-08-18, and
+        the first date must not be after the last date.
+        """
+    end
+  end
+
+  defp validate_recurring_holidays!(%{} = map) do
+    case Map.get(map, "recurring_holidays") do
+      nil ->
+        %{}
+
+      %{} = holiday_map ->
+        Map.new(holiday_map, fn {month_day_str, description} ->
+          {parse_month_day!(month_day_str), description}
         end)
     end
   end
 
+  defp parse_month_day!(month_day_str) do
+    case Date.from_iso8601("2000-#{month_day_str}") do
+      {:ok, %Date{month: month, day: day}} ->
+        {month, day}
+
+      {:error, _} ->
+        raise """
+        Invalid recurring holiday
+
+        The configuration file sets an invalid recurring holiday:
+        #{month_day_str}
+
+        The date must be given as month and day, for example 12-25.
+        """
+    end
+  end
+
   defp validate_start_date!(%{"start_date" => start_date}) do
     Date.from_iso8601!(start_date)
   end
 
   defp validate_time_adjustment!(%{"time_adjustment" => s}) do
-    parts = String.split(s, " ")
-
-    Enum.reduce(parts, 0, fn part, seconds ->
-      case Integer.parse(part) do
-        {n, "d"} -> n * @seconds_per_day + seconds
-        {n, "h"} -> n * @seconds_per_hour + seconds
-        {n, "m"} -> n * @seconds_per_minute + seconds
-        {n, "s"} -> n + seconds
+    case parse_duration(s) do
+      {:ok, seconds} ->
+        seconds
+
+      :error ->
+        raise """
+        Invalid time adjustment
+
+        The configuration file sets an invalid time adjustment:
+        #{inspect(s)}
+
+        The value must be a duration with units, for example 2h 30m.
+        """
+    end
+  end
+
+  defp validate_project_targets!(%{} = map) do
+    case Map.get(map, "project_targets") do
+      nil ->
+        %{}
+
+      %{} = target_map ->
+        Map.new(target_map, &validate_project_target!/1)
+    end
+  end
+
+  defp validate_project_target!({project, duration}) do
+    case parse_duration(duration) do
+      {:ok, seconds} ->
+        {to_string(project), seconds}
+
+      :error ->
+        raise """
+        Invalid project target
+
+        The configuration file sets an invalid target for project #{project}:
+        #{inspect(duration)}
+
+        The target must be a duration with units, for example 40h 30m.
+        """
+    end
+  end
+
+  defp parse_duration(s) when is_binary(s) do
+    s
+    |> String.split(" ")
+    |> Enum.reduce_while({:ok, 0}, fn part, {:ok, seconds} ->
+      case parse_duration_part(part) do
+        {:ok, part_seconds} -> {:cont, {:ok, seconds + part_seconds}}
+        :error -> {:halt, :error}
       end
     end)
   end
 
+  defp parse_duration(_), do: :error
+
+  defp parse_duration_part(part) do
+    case Integer.parse(part) do
+      {n, "d"} -> {:ok, n * @seconds_per_day}
+      {n, "h"} -> {:ok, n * @seconds_per_hour}
+      {n, "m"} -> {:ok, n * @seconds_per_minute}
+      {n, "s"} -> {:ok, n}
+      _ -> :error
+    end
+  end
+
   defp validate_time_zone!(%{"time_zone" => tz}) do
     unless Tzdata.canonical_zone?(tz) do
       raise """
@@ -127,13 +386,30 @@ defmodule Ebb.Configuration do
 
   defp validate_working_days!(%{"working_days" => map}) do
     %{
-      1 => Map.fetch!(map, "monday"),
-      2 => Map.fetch!(map, "tuesday"),
-      3 => Map.fetch!(map, "wednesday"),
-      4 => Map.fetch!(map, "thursday"),
-      5 => Map.fetch!(map, "friday"),
-      6 => Map.fetch!(map, "saturday"),
-      7 => Map.fetch!(map, "sunday")
+      1 => fetch_working_hours!(map, "monday"),
+      2 => fetch_working_hours!(map, "tuesday"),
+      3 => fetch_working_hours!(map, "wednesday"),
+      4 => fetch_working_hours!(map, "thursday"),
+      5 => fetch_working_hours!(map, "friday"),
+      6 => fetch_working_hours!(map, "saturday"),
+      7 => fetch_working_hours!(map, "sunday")
     }
   end
+
+  defp fetch_working_hours!(map, weekday) do
+    hours = Map.get(map, weekday)
+
+    unless is_number(hours) and hours >= 0 and hours <= 24 do
+      raise """
+      Invalid working hours
+
+      The configuration file sets invalid working hours for #{weekday}:
+      #{inspect(hours)}
+
+      The value must be a number between 0 and 24.
+      """
+    end
+
+    hours
+  end
 end
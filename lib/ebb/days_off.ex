@@ -5,7 +5,7 @@ defmodule Ebb.DaysOff do
 
   alias Ebb.Configuration
 
-  @type days_off_summary :: %{allowed: integer, taken: integer, left: integer}
+  @type days_off_summary :: %{allowed: number, taken: number, left: number}
 
   @doc """
   Takes a year and the configuration and returns a summary of the allowed, taken
@@ -18,11 +18,34 @@ defmodule Ebb.DaysOff do
   """
   @spec calculate_vacation_days(integer, Configuration.t()) ::
           days_off_summary()
-  def calculate_vacation_days(year, %Configuration{
+  def calculate_vacation_days(
+        year,
+        %Configuration{vacation_days: vacation_days} = config
+      ) do
+    allowed_vacation_days = effective_vacation_days(year, config)
+    calculate_taken_and_left_days(year, allowed_vacation_days, vacation_days)
+  end
+
+  @doc """
+  Takes a year and the configuration and returns the number of vacation days
+  allowed for that year, including vacation days carried over from the
+  previous year.
+
+  ## Example
+
+      iex> config = %Ebb.Configuration{
+      ...>   allowed_days_off: %{sick_days: 10, vacation_days: 30},
+      ...>   vacation_carryover: %{2082 => 5}
+      ...> }
+      iex> effective_vacation_days(2082, config)
+      35
+  """
+  @spec effective_vacation_days(integer, Configuration.t()) :: number
+  def effective_vacation_days(year, %Configuration{
         allowed_days_off: %{vacation_days: allowed_vacation_days},
-        vacation_days: vacation_days
+        vacation_carryover: vacation_carryover
       }) do
-    calculate_taken_and_left_days(year, allowed_vacation_days, vacation_days)
+    allowed_vacation_days + Map.get(vacation_carryover, year, 0)
   end
 
   @doc """
@@ -43,6 +66,29 @@ defmodule Ebb.DaysOff do
     calculate_taken_and_left_days(year, allowed_sick_days, sick_days)
   end
 
+  @doc """
+  Takes the configuration and a month and returns the configuration with only
+  the vacation days and sick days that fall into that month of any year.
+
+  ## Example
+
+      iex> filter_by_month(%Ebb.Configuration{}, 3)
+      %Ebb.Configuration{}
+  """
+  @spec filter_by_month(Configuration.t(), Calendar.month()) ::
+          Configuration.t()
+  def filter_by_month(%Configuration{} = config, month) do
+    %{
+      config
+      | vacation_days: filter_dates_by_month(config.vacation_days, month),
+        sick_days: filter_dates_by_month(config.sick_days, month)
+    }
+  end
+
+  defp filter_dates_by_month(dates, month) do
+    Map.filter(dates, fn {date, _} -> date.month == month end)
+  end
+
   defp calculate_taken_and_left_days(year, allowed_days, dates) do
     taken_days = dates |> Enum.map(&day_factor(&1, year)) |> Enum.sum()
     days_left = allowed_days - taken_days
@@ -51,11 +97,13 @@ defmodule Ebb.DaysOff do
 
   defp day_factor({date, description}, year) do
     if date.year == year do
-      if String.ends_with?(description, " (h)"),
-        do: 0.5,
-        else: 1
+      portion_factor(Configuration.day_portion(description))
     else
       0
     end
   end
+
+  defp portion_factor(:full), do: 1
+  defp portion_factor(:half), do: 0.5
+  defp portion_factor(:quarter), do: 0.25
 end
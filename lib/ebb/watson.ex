@@ -10,13 +10,17 @@ defmodule Ebb.Watson do
   set in the configuration.
 
   Returns the start date and the total logged time in seconds.
+
+  ## Options
+
+  - `:project` - Only include frames of the given project.
   """
-  @spec report(Configuration.t()) :: %{
+  @spec report(Configuration.t(), keyword) :: %{
           start_date: Date.t(),
           total_time_in_seconds: integer
         }
-  def report(%Configuration{} = config) do
-    watson_report = Jason.decode!(run_watson_report(config.start_date))
+  def report(%Configuration{} = config, opts \\ []) do
+    watson_report = Jason.decode!(run_watson_report(config.start_date, opts))
 
     %{
       start_date: fetch_start_date!(watson_report, config),
@@ -24,11 +28,20 @@ defmodule Ebb.Watson do
     }
   end
 
-  defp run_watson_report(%Date{} = start_date) do
+  defp run_watson_report(%Date{} = start_date, opts) do
     from = Date.to_iso8601(start_date)
 
+    project_args =
+      case Keyword.fetch(opts, :project) do
+        {:ok, project} -> ["--project", project]
+        :error -> []
+      end
+
     {result, 0} =
-      System.cmd("watson", ["report", "--json", "--current", "--from", from])
+      System.cmd(
+        "watson",
+        ["report", "--json", "--current", "--from", from] ++ project_args
+      )
 
     result
   end
@@ -40,6 +40,28 @@ defmodule Ebb.WorkingHours do
       @seconds_per_hour
   end
 
+  @doc """
+  Returns the number of working days between the given dates (inclusive).
+
+  Days without configured working hours and full-day holidays are not counted.
+  """
+  @spec count_working_days(Date.t(), Date.t(), Configuration.t()) ::
+          non_neg_integer
+  def count_working_days(
+        first_date,
+        last_date,
+        %Configuration{working_days: working_days} = config
+      ) do
+    holidays = Configuration.holidays_between(config, first_date, last_date)
+
+    first_date
+    |> Date.range(last_date)
+    |> Enum.count(fn date ->
+      get_hours_for_day(date, working_days) > 0 and
+        not Configuration.full_day_holiday?(holidays, date)
+    end)
+  end
+
   defp calculate_weeks_and_days(end_date, start_date) do
     days_diff = Date.diff(end_date, start_date) + 1
     full_weeks = div(days_diff, 7)
@@ -69,36 +91,40 @@ defmodule Ebb.WorkingHours do
            config
        ) do
     config
-    |> get_days_off()
+    |> get_days_off(start_date, end_date)
     |> filter_dates_in_range(start_date, end_date)
     |> Enum.map(&get_hours_for_day(&1, working_days))
     |> Enum.sum()
   end
 
   defp get_hours_for_day(date, working_days) do
-    {date, half_or_full} =
+    {date, portion} =
       case date do
         {_, _} = v -> v
         d -> {d, :full}
       end
 
     hours = Map.fetch!(working_days, Date.day_of_week(date))
-    if half_or_full == :half, do: 0.5 * hours, else: hours
+
+    case portion do
+      :full -> hours
+      :half -> 0.5 * hours
+      :quarter -> 0.25 * hours
+    end
   end
 
-  defp get_days_off(%Configuration{
-         holidays: holidays,
-         sick_days: sick_days,
-         vacation_days: vacation_days
-       }) do
-    holidays
+  defp get_days_off(
+         %Configuration{sick_days: sick_days, vacation_days: vacation_days} =
+           config,
+         start_date,
+         end_date
+       ) do
+    config
+    |> Configuration.holidays_between(start_date, end_date)
     |> Map.merge(sick_days)
     |> Map.merge(vacation_days)
-    |> Enum.map(fn
-      {date, description} ->
-        if String.ends_with?(description, " (h)"),
-          do: {date, :half},
-          else: {date, :full}
+    |> Enum.map(fn {date, description} ->
+      {date, Configuration.day_portion(description)}
     end)
   end
 
@@ -0,0 +1,319 @@
+defmodule Ebb.CLITest do
+  use ExUnit.Case
+
+  import ExUnit.CaptureIO
+
+  alias Ebb.CLI
+
+  @moduletag :tmp_dir
+
+  setup %{tmp_dir: tmp_dir} do
+    File.write!(Path.join(tmp_dir, "config.yml"), """
+    time_zone: Asia/Tokyo
+    start_date: 2025-01-01
+    time_adjustment: 0h 0m
+    working_days:
+      monday: 8
+      tuesday: 8
+      wednesday: 8
+      thursday: 8
+      friday: 8
+      saturday: 0
+      sunday: 0
+    vacation_days:
+      2025-03-03: Ski trip
+      2025-03-04: Ski trip (h)
+      2025-08-11: Summer vacation
+    sick_days:
+      2025-03-10: Cold
+    allowed_days_off:
+      vacation_days: 30
+      sick_days: 10
+    project_targets:
+      client-project: 1h
+    """)
+
+    System.put_env("EBB_CONFIG_PATH", tmp_dir)
+    on_exit(fn -> System.delete_env("EBB_CONFIG_PATH") end)
+  end
+
+  describe "daysoff" do
+    test "rejects options with invalid values" do
+      assert capture_usage_error(["daysoff", "--year", "abc"]) =~
+               "Invalid options: --year abc"
+    end
+
+    test "rejects unknown options" do
+      assert capture_usage_error(["daysoff", "--yaer", "2024"]) =~
+               "Invalid options: --yaer"
+    end
+
+    test "rejects months outside of 1 to 12" do
+      assert capture_usage_error(["daysoff", "--month", "13"]) =~
+               "Invalid month: 13"
+    end
+
+    test "rejects unknown formats" do
+      output = capture_usage_error(["daysoff", "--format", "xml"])
+      assert output =~ "Invalid format: xml"
+      assert output =~ "Allowed formats: text, json"
+    end
+
+    test "formats are case-sensitive" do
+      assert capture_usage_error(["daysoff", "--format", "JSON"]) =~
+               "Invalid format: JSON"
+    end
+  end
+
+  describe "daysoff --format text" do
+    test "prints whole numbers without decimals", %{tmp_dir: tmp_dir} do
+      config_path = Path.join(tmp_dir, "config.yml")
+
+      config_path
+      |> File.read!()
+      |> String.replace("Summer vacation", "Summer vacation (h)")
+      |> then(&File.write!(config_path, &1))
+
+      output = capture_io(fn -> CLI.main(["daysoff", "--year", "2025"]) end)
+
+      assert output =~ ~r/^Taken:\s+2$/m
+      assert output =~ ~r/^Left:\s+28$/m
+      refute output =~ ".0"
+    end
+  end
+
+  describe "daysoff --format json" do
+    test "prints the days off summary for the year" do
+      output =
+        capture_io(fn ->
+          CLI.main(["daysoff", "--year", "2025", "--format", "json"])
+        end)
+
+      assert Jason.decode!(output) == %{
+               "year" => 2025,
+               "sick_days_allowed" => 10,
+               "sick_days_taken" => 1,
+               "sick_days_remaining" => 9,
+               "vacation_days_allowed" => 30,
+               "vacation_days_taken" => 2.5,
+               "vacation_days_remaining" => 27.5
+             }
+    end
+
+    test "prints the days off taken in a month" do
+      output =
+        capture_io(fn ->
+          CLI.main([
+            "daysoff",
+            "--year",
+            "2025",
+            "--month",
+            "3",
+            "--format",
+            "json"
+          ])
+        end)
+
+      assert Jason.decode!(output) == %{
+               "year" => 2025,
+               "month" => 3,
+               "working_days" => 21,
+               "sick_days_taken" => 1,
+               "vacation_days_taken" => 1.5
+             }
+    end
+  end
+
+  describe "daysoff --remaining-only" do
+    test "prints the remaining vacation days" do
+      assert capture_io(fn ->
+               CLI.main(["daysoff", "--year", "2025", "--remaining-only"])
+             end) == "27.5\n"
+    end
+
+    test "prints the remaining sick days" do
+      assert capture_io(fn ->
+               CLI.main([
+                 "daysoff",
+                 "--year",
+                 "2025",
+                 "--remaining-only",
+                 "--category",
+                 "sick"
+               ])
+             end) == "9\n"
+    end
+
+    test "prints both categories as JSON" do
+      output =
+        capture_io(fn ->
+          CLI.main([
+            "daysoff",
+            "--year",
+            "2025",
+            "--remaining-only",
+            "--format",
+            "json"
+          ])
+        end)
+
+      assert Jason.decode!(output) == %{
+               "sick_days_remaining" => 9,
+               "vacation_days_remaining" => 27.5
+             }
+    end
+
+    test "prints the remaining vacation days as JSON" do
+      output =
+        capture_io(fn ->
+          CLI.main([
+            "daysoff",
+            "--year",
+            "2025",
+            "--remaining-only",
+            "--category",
+            "vacation",
+            "--format",
+            "json"
+          ])
+        end)
+
+      assert Jason.decode!(output) == %{"vacation_days_remaining" => 27.5}
+    end
+
+    test "rejects unknown categories" do
+      output =
+        capture_usage_error([
+          "daysoff",
+          "--remaining-only",
+          "--category",
+          "foo"
+        ])
+
+      assert output =~ "Invalid category: foo"
+      assert output =~ "Allowed categories: vacation, sick"
+    end
+
+    test "rejects --month" do
+      assert capture_usage_error([
+               "daysoff",
+               "--remaining-only",
+               "--month",
+               "3"
+             ]) =~ "cannot be used with --month"
+    end
+
+    test "rejects --category without --remaining-only" do
+      assert capture_usage_error(["daysoff", "--category", "sick"]) =~
+               "The --category option requires --remaining-only"
+    end
+  end
+
+  describe "balance --project" do
+    setup %{tmp_dir: tmp_dir} do
+      bin_dir = Path.join(tmp_dir, "bin")
+      File.mkdir_p!(bin_dir)
+      watson_path = Path.join(bin_dir, "watson")
+
+      File.write!(watson_path, """
+      #!/bin/sh
+      echo "$@" > "#{bin_dir}/args"
+      cat "#{bin_dir}/report.json"
+      """)
+
+      File.chmod!(watson_path, 0o755)
+
+      path = System.get_env("PATH")
+      System.put_env("PATH", bin_dir <> ":" <> path)
+      on_exit(fn -> System.put_env("PATH", path) end)
+
+      %{bin_dir: bin_dir}
+    end
+
+    test "prefixes a positive balance with a plus sign", %{bin_dir: bin_dir} do
+      assert project_balance(bin_dir, 5400) =~ ~r/^Balance:\s+\+30m 00s$/m
+    end
+
+    test "prints a zero balance without a sign", %{bin_dir: bin_dir} do
+      assert project_balance(bin_dir, 3600) =~ ~r/^Balance:\s+00s$/m
+    end
+
+    test "prints a negative balance with a minus sign", %{bin_dir: bin_dir} do
+      assert project_balance(bin_dir, 1800) =~ ~r/^Balance:\s+-30m 00s$/m
+    end
+
+    test "does not prefix a balance that rounds to zero", %{bin_dir: bin_dir} do
+      assert project_balance(bin_dir, 3600.4) =~ ~r/^Balance:\s+00s$/m
+    end
+
+    test "passes the project to watson", %{bin_dir: bin_dir} do
+      project_balance(bin_dir, 3600)
+
+      assert File.read!(Path.join(bin_dir, "args")) ==
+               "report --json --current --from 2025-01-01 " <>
+                 "--project client-project\n"
+    end
+
+    test "prints only the actual time without a target", %{bin_dir: bin_dir} do
+      output = project_balance(bin_dir, 5400, "other-project")
+
+      assert output =~ ~r/^Project:\s+other-project$/m
+      assert output =~ ~r/^Actual:\s+1h 30m 00s$/m
+      refute output =~ "Target:"
+      refute output =~ "Balance:"
+    end
+  end
+
+  describe "config validate" do
+    test "confirms a valid configuration" do
+      assert capture_io(fn -> CLI.main(["config", "validate"]) end) ==
+               "Configuration is valid.\n"
+    end
+
+    test "reports an invalid configuration", %{tmp_dir: tmp_dir} do
+      config_path = Path.join(tmp_dir, "config.yml")
+
+      config_path
+      |> File.read!()
+      |> String.replace("monday: 8", "monday: 25")
+      |> then(&File.write!(config_path, &1))
+
+      output = capture_usage_error(["config", "validate"])
+      assert output =~ "Invalid working hours"
+      assert output =~ "monday"
+    end
+
+    test "reports errors without a custom message", %{tmp_dir: tmp_dir} do
+      config_path = Path.join(tmp_dir, "config.yml")
+
+      config_path
+      |> File.read!()
+      |> String.replace("start_date: 2025-01-01", "start_date: tomorrow")
+      |> then(&File.write!(config_path, &1))
+
+      assert capture_usage_error(["config", "validate"]) =~ "tomorrow"
+    end
+  end
+
+  defp project_balance(bin_dir, time, project \\ "client-project") do
+    File.write!(
+      Path.join(bin_dir, "report.json"),
+      Jason.encode!(%{
+        timespan: %{
+          from: "2025-01-01T00:00:00+09:00",
+          to: "2025-01-31T00:00:00+09:00"
+        },
+        time: time,
+        projects: []
+      })
+    )
+
+    capture_io(fn -> CLI.main(["balance", "--project", project]) end)
+  end
+
+  defp capture_usage_error(args) do
+    capture_io(:stderr, fn ->
+      assert catch_exit(CLI.main(args)) == {:shutdown, 1}
+    end)
+  end
+end
@@ -0,0 +1,296 @@
+defmodule Ebb.ConfigurationTest do
+  use ExUnit.Case
+
+  alias Ebb.Configuration
+
+  @moduletag :tmp_dir
+
+  setup %{tmp_dir: tmp_dir} do
+    System.put_env("EBB_CONFIG_PATH", tmp_dir)
+    on_exit(fn -> System.delete_env("EBB_CONFIG_PATH") end)
+  end
+
+  describe "read_config/0" do
+    test "rejects more than 24 working hours per day", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, "", monday_hours: 25)
+
+      assert_raise RuntimeError, ~r/Invalid working hours.*monday/s, fn ->
+        Configuration.read_config()
+      end
+    end
+
+    test "rejects negative working hours", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, "", monday_hours: -1)
+
+      assert_raise RuntimeError, ~r/Invalid working hours.*monday/s, fn ->
+        Configuration.read_config()
+      end
+    end
+
+    test "rejects negative allowed days off", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, "", allowed_vacation_days: -1)
+
+      assert_raise RuntimeError, ~r/Invalid number of allowed days off/, fn ->
+        Configuration.read_config()
+      end
+    end
+
+    test "rejects non-numeric allowed days off", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, "", allowed_vacation_days: "thirty")
+
+      assert_raise RuntimeError, ~r/Invalid number of allowed days off/, fn ->
+        Configuration.read_config()
+      end
+    end
+
+    test "accepts fractional allowed days off", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, "", allowed_vacation_days: 22.5)
+
+      assert %Configuration{allowed_days_off: %{vacation_days: 22.5}} =
+               Configuration.read_config()
+    end
+
+    test "expands date ranges to working days", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      vacation_days:
+        2023-07-27..2023-08-01: Summer vacation
+        2023-07-31: Summer vacation (h)
+      """)
+
+      assert %Configuration{vacation_days: vacation_days} =
+               Configuration.read_config()
+
+      assert vacation_days == %{
+               ~D[2023-07-27] => "Summer vacation",
+               ~D[2023-07-28] => "Summer vacation",
+               ~D[2023-07-31] => "Summer vacation (h)",
+               ~D[2023-08-01] => "Summer vacation"
+             }
+    end
+
+    test "skips full-day holidays in date ranges", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      holidays:
+        2023-12-27: Year-end party (h)
+      recurring_holidays:
+        12-25: Christmas Day
+      vacation_days:
+        2023-12-22..2023-12-29: Winter vacation
+      """)
+
+      assert %Configuration{vacation_days: vacation_days} =
+               Configuration.read_config()
+
+      assert vacation_days == %{
+               ~D[2023-12-22] => "Winter vacation",
+               ~D[2023-12-26] => "Winter vacation",
+               ~D[2023-12-27] => "Winter vacation",
+               ~D[2023-12-28] => "Winter vacation",
+               ~D[2023-12-29] => "Winter vacation"
+             }
+    end
+
+    test "rejects reversed date ranges", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      vacation_days:
+        2023-08-18..2023-08-14: Summer vacation
+      """)
+
+      assert_raise RuntimeError, ~r/Invalid date range.*2023-08-18/s, fn ->
+        Configuration.read_config()
+      end
+    end
+
+    test "rejects malformed date ranges", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      vacation_days:
+        2023-08-14..2023-08-16..2023-08-18: Summer vacation
+      """)
+
+      assert_raise RuntimeError, ~r/Invalid date range/, fn ->
+        Configuration.read_config()
+      end
+    end
+
+    test "expands sick day ranges", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      sick_days:
+        2025-03-10..2025-03-16: Flu
+      """)
+
+      assert %Configuration{sick_days: sick_days} = Configuration.read_config()
+
+      assert sick_days == %{
+               ~D[2025-03-10] => "Flu",
+               ~D[2025-03-11] => "Flu",
+               ~D[2025-03-12] => "Flu",
+               ~D[2025-03-13] => "Flu",
+               ~D[2025-03-14] => "Flu"
+             }
+    end
+
+    test "parses recurring holidays", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      recurring_holidays:
+        12-25: Christmas Day
+        02-29: Leap Day
+      """)
+
+      assert %Configuration{recurring_holidays: recurring_holidays} =
+               Configuration.read_config()
+
+      assert recurring_holidays == %{
+               {12, 25} => "Christmas Day",
+               {2, 29} => "Leap Day"
+             }
+    end
+
+    test "rejects invalid recurring holidays", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      recurring_holidays:
+        12/25: Christmas Day
+      """)
+
+      assert_raise RuntimeError, ~r/Invalid recurring holiday.*12\/25/s, fn ->
+        Configuration.read_config()
+      end
+    end
+
+    test "parses project targets as seconds", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      project_targets:
+        client-project: 40h 30m
+      """)
+
+      assert %Configuration{project_targets_in_seconds: targets} =
+               Configuration.read_config()
+
+      assert targets == %{"client-project" => 145_800}
+    end
+
+    test "raises on project targets without units", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      project_targets:
+        client-project: 40
+      """)
+
+      assert_raise RuntimeError,
+                   ~r/Invalid project target.*client-project:\n40\n/s,
+                   fn -> Configuration.read_config() end
+    end
+
+    test "raises on quoted targets without units", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      project_targets:
+        client-project: "40"
+      """)
+
+      assert_raise RuntimeError,
+                   ~r/Invalid project target.*client-project:\n"40"\n/s,
+                   fn -> Configuration.read_config() end
+    end
+
+    test "raises on fractional project targets", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      project_targets:
+        client-project: 1.5h
+      """)
+
+      assert_raise RuntimeError,
+                   ~r/Invalid project target.*client-project:\n"1.5h"\n/s,
+                   fn -> Configuration.read_config() end
+    end
+  end
+
+  describe "vacation carry-over" do
+    test "parses carried-over days by year", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      vacation_carryover:
+        2024: 2.5
+      """)
+
+      assert %Configuration{vacation_carryover: %{2024 => 2.5}} =
+               Configuration.read_config()
+    end
+
+    test "raises on keys that are not years", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      vacation_carryover:
+        last-year: 5
+      """)
+
+      assert_raise RuntimeError,
+                   ~r/Invalid vacation carry-over.*"last-year": 5/s,
+                   fn -> Configuration.read_config() end
+    end
+
+    test "raises on negative values", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      vacation_carryover:
+        2024: -3
+      """)
+
+      assert_raise RuntimeError,
+                   ~r/Invalid vacation carry-over.*2024: -3/s,
+                   fn -> Configuration.read_config() end
+    end
+
+    test "raises on values that are not maps", %{tmp_dir: tmp_dir} do
+      write_config!(tmp_dir, """
+      vacation_carryover: 5
+      """)
+
+      assert_raise RuntimeError,
+                   ~r/Invalid vacation carry-over.*\n5\n/s,
+                   fn -> Configuration.read_config() end
+    end
+  end
+
+  describe "holidays_for_year/2" do
+    test "adds recurring holidays to the holidays of the year" do
+      config = %Configuration{
+        holidays: %{
+          ~D[2030-12-25] => "Christmas Day (h)",
+          ~D[2031-01-01] => "New Year's Day"
+        },
+        recurring_holidays: %{
+          {12, 25} => "Christmas Day",
+          {2, 29} => "Leap Day"
+        }
+      }
+
+      assert Configuration.holidays_for_year(config, 2032) == %{
+               ~D[2032-02-29] => "Leap Day",
+               ~D[2032-12-25] => "Christmas Day"
+             }
+
+      assert Configuration.holidays_for_year(config, 2030) == %{
+               ~D[2030-12-25] => "Christmas Day (h)"
+             }
+    end
+  end
+
+  defp write_config!(tmp_dir, extra, opts \\ []) do
+    monday_hours = Keyword.get(opts, :monday_hours, 8)
+    allowed_vacation_days = Keyword.get(opts, :allowed_vacation_days, 30)
+
+    config = """
+    time_zone: Asia/Tokyo
+    start_date: 2023-01-01
+    time_adjustment: 0h 0m
+    working_days:
+      monday: #{monday_hours}
+      tuesday: 8
+      wednesday: 8
+      thursday: 8
+      friday: 4
+      saturday: 0
+      sunday: 0
+    allowed_days_off:
+      vacation_days: #{allowed_vacation_days}
+      sick_days: 10
+    """
+
+    File.write!(Path.join(tmp_dir, "config.yml"), config <> extra)
+  end
+end
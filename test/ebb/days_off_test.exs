@@ -26,6 +26,42 @@ defmodule Ebb.DaysOffTest do
                left: 47.5
              }
     end
+
+    test "counts quarter days as a quarter of a day" do
+      year = 2462
+
+      config =
+        %Configuration{
+          allowed_days_off: %{vacation_days: 50},
+          vacation_days: %{
+            ~D[2462-01-01] => "New Year's Day",
+            ~D[2462-06-24] => "Midsummer (h)",
+            ~D[2462-07-01] => "Dentist (q)",
+            ~D[2462-07-02] => "Parcel pickup (q)"
+          }
+        }
+
+      assert DaysOff.calculate_vacation_days(year, config) == %{
+               allowed: 50,
+               taken: 2.0,
+               left: 48.0
+             }
+    end
+
+    test "adds carried over vacation days to the allowed days" do
+      config =
+        %Configuration{
+          allowed_days_off: %{vacation_days: 30},
+          vacation_carryover: %{2461 => 2, 2462 => 4.5},
+          vacation_days: %{~D[2462-01-01] => "New Year's Day"}
+        }
+
+      assert DaysOff.calculate_vacation_days(2462, config) == %{
+               allowed: 34.5,
+               taken: 1,
+               left: 33.5
+             }
+    end
   end
 
   describe "calculate_sick_days/2" do
@@ -51,4 +87,30 @@ defmodule Ebb.DaysOffTest do
              }
     end
   end
+
+  describe "filter_by_month/2" do
+    test "keeps only vacation and sick days in the given month" do
+      config =
+        %Configuration{
+          vacation_days: %{
+            ~D[2462-02-28] => "Ski trip",
+            ~D[2462-03-01] => "Ski trip",
+            ~D[2463-03-15] => "Next ski trip"
+          },
+          sick_days: %{
+            ~D[2462-03-31] => "Cold (h)",
+            ~D[2462-04-01] => "Cold"
+          }
+        }
+
+      filtered_config = DaysOff.filter_by_month(config, 3)
+
+      assert filtered_config.vacation_days == %{
+               ~D[2462-03-01] => "Ski trip",
+               ~D[2463-03-15] => "Next ski trip"
+             }
+
+      assert filtered_config.sick_days == %{~D[2462-03-31] => "Cold (h)"}
+    end
+  end
 end
@@ -0,0 +1,42 @@
+defmodule Ebb.WorkingHoursTest do
+  use ExUnit.Case, async: true
+
+  alias Ebb.Configuration
+  alias Ebb.WorkingHours
+
+  describe "calculate_expected_work_seconds/2" do
+    test "subtracts a quarter of the working hours for quarter days" do
+      config = %Configuration{
+        start_date: ~D[2025-03-03],
+        time_adjustment_in_seconds: 0,
+        working_days: %{1 => 8, 2 => 8, 3 => 8, 4 => 8, 5 => 8, 6 => 0, 7 => 0},
+        vacation_days: %{~D[2025-03-05] => "Dentist (q)"}
+      }
+
+      assert WorkingHours.calculate_expected_work_seconds(
+               ~D[2025-03-07],
+               config
+             ) == 38 * 3600
+    end
+  end
+
+  describe "count_working_days/3" do
+    test "skips days without working hours and full-day holidays" do
+      config = %Configuration{
+        working_days: %{1 => 8, 2 => 8, 3 => 8, 4 => 8, 5 => 4, 6 => 0, 7 => 0},
+        holidays: %{
+          ~D[2025-03-05] => "Founding Day",
+          ~D[2025-03-12] => "Company outing (h)",
+          ~D[2025-03-15] => "Weekend holiday"
+        },
+        recurring_holidays: %{{3, 7} => "Spring Day"}
+      }
+
+      assert WorkingHours.count_working_days(
+               ~D[2025-03-03],
+               ~D[2025-03-16],
+               config
+             ) == 8
+    end
+  end
+end